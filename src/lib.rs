@@ -32,6 +32,25 @@ impl std::error::Error for Error {}
 #[derive(Debug)]
 pub struct Decoder {
     dec: *mut Dav1dContext,
+    // Kept alive for as long as the decoder so the `Dav1dPicAllocator` cookie
+    // handed to dav1d stays valid; each picture from `get_picture` also holds
+    // a clone of it.
+    _allocator: Option<Arc<Box<dyn PicAllocator + Send + Sync>>>,
+    // An access unit that `dav1d_send_data` has not yet fully consumed; resent
+    // by `send_pending` once pictures have been drained.
+    pending_data: Option<Dav1dData>,
+}
+
+/// Outcome of feeding data to the decoder.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SendState {
+    /// dav1d took the whole access unit; you may send the next one.
+    Accepted,
+    /// The access unit is not fully delivered (dav1d returned `EAGAIN`, kept
+    /// part of the buffer, or an earlier access unit is still pending): drain
+    /// pictures with [`Decoder::try_get_picture`], then retry via
+    /// [`Decoder::send_pending`].
+    NeedPicturesDrained,
 }
 
 unsafe extern "C" fn release_wrapped_data(_data: *const u8, cookie: *mut c_void) {
@@ -39,31 +58,233 @@ unsafe extern "C" fn release_wrapped_data(_data: *const u8, cookie: *mut c_void)
     closure();
 }
 
-impl Default for Decoder {
+/// Plane pointers and strides produced by a [`PicAllocator`].
+///
+/// `data`/`stride` follow the `Dav1dPicture` layout: three plane pointers and
+/// two strides (luma in `[0]`, shared chroma in `[1]`). Each plane pointer must
+/// be aligned to `DAV1D_PICTURE_ALIGNMENT` (64) bytes.
+pub struct AllocatedPlanes {
+    pub data: [*mut c_void; 3],
+    pub stride: [isize; 2],
+    /// Opaque cookie handed back to [`PicAllocator::release`] when the picture
+    /// is unreferenced; use it to locate the backing buffer.
+    pub allocator_data: *mut c_void,
+}
+
+/// Geometry of a picture dav1d is about to decode into, passed to
+/// [`PicAllocator::alloc`].
+pub struct PictureParameters {
+    params: Dav1dPictureParameters,
+}
+
+impl PictureParameters {
+    pub fn width(&self) -> u32 {
+        self.params.w as u32
+    }
+
+    pub fn height(&self) -> u32 {
+        self.params.h as u32
+    }
+
+    pub fn bit_depth(&self) -> usize {
+        self.params.bpc as usize
+    }
+
+    pub fn pixel_layout(&self) -> PixelLayout {
+        pixel_layout_from_ffi(self.params.layout)
+    }
+}
+
+/// Allocates the plane buffers backing decoded pictures.
+///
+/// Install one with [`Settings::set_pic_allocator`] to place frames in
+/// user-owned memory (a reused pool, pinned/GPU-mapped memory, a ring buffer,
+/// …). `alloc` must honor `DAV1D_PICTURE_ALIGNMENT` (64 bytes) for every plane
+/// pointer and the stride dav1d expects, and must keep the buffer alive until
+/// the matching `release` fires.
+pub trait PicAllocator {
+    fn alloc(&self, params: &PictureParameters) -> Result<AllocatedPlanes, Error>;
+    fn release(&self, cookie: *mut c_void);
+}
+
+unsafe extern "C" fn alloc_picture_callback(
+    pic: *mut Dav1dPicture,
+    cookie: *mut c_void,
+) -> std::os::raw::c_int {
+    let allocator = &*(cookie as *const Box<dyn PicAllocator + Send + Sync>);
+    let params = PictureParameters { params: (*pic).p };
+    match allocator.alloc(&params) {
+        Ok(planes) => {
+            (*pic).data = planes.data;
+            (*pic).stride = planes.stride;
+            (*pic).allocator_data = planes.allocator_data;
+            0
+        }
+        Err(e) => e.0,
+    }
+}
+
+unsafe extern "C" fn release_picture_callback(pic: *mut Dav1dPicture, cookie: *mut c_void) {
+    let allocator = &*(cookie as *const Box<dyn PicAllocator + Send + Sync>);
+    allocator.release((*pic).allocator_data);
+}
+
+/// Which in-loop filters dav1d should apply while decoding.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum InloopFilterType {
+    None,
+    Deblock,
+    Cdef,
+    Restoration,
+    All,
+}
+
+impl InloopFilterType {
+    fn to_ffi(self) -> u32 {
+        let value = match self {
+            InloopFilterType::None => Dav1dInloopFilterType_DAV1D_INLOOPFILTER_NONE,
+            InloopFilterType::Deblock => Dav1dInloopFilterType_DAV1D_INLOOPFILTER_DEBLOCK,
+            InloopFilterType::Cdef => Dav1dInloopFilterType_DAV1D_INLOOPFILTER_CDEF,
+            InloopFilterType::Restoration => Dav1dInloopFilterType_DAV1D_INLOOPFILTER_RESTORATION,
+            InloopFilterType::All => Dav1dInloopFilterType_DAV1D_INLOOPFILTER_ALL,
+        };
+        value as u32
+    }
+}
+
+/// Which frame types dav1d should decode and output.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DecodeFrameType {
+    All,
+    Reference,
+    Intra,
+    Key,
+}
+
+impl DecodeFrameType {
+    fn to_ffi(self) -> u32 {
+        let value = match self {
+            DecodeFrameType::All => Dav1dDecodeFrameType_DAV1D_DECODEFRAMETYPE_ALL,
+            DecodeFrameType::Reference => Dav1dDecodeFrameType_DAV1D_DECODEFRAMETYPE_REFERENCE,
+            DecodeFrameType::Intra => Dav1dDecodeFrameType_DAV1D_DECODEFRAMETYPE_INTRA,
+            DecodeFrameType::Key => Dav1dDecodeFrameType_DAV1D_DECODEFRAMETYPE_KEY,
+        };
+        value as u32
+    }
+}
+
+/// Decoder settings, wrapping `Dav1dSettings` with typed setters.
+///
+/// Start from [`Settings::new`] (which calls `dav1d_default_settings`), tune the
+/// fields you care about, then hand it to [`Decoder::with_settings`].
+#[derive(Clone)]
+pub struct Settings {
+    dav1d_settings: Dav1dSettings,
+    allocator: Option<Arc<Box<dyn PicAllocator + Send + Sync>>>,
+}
+
+unsafe impl Send for Settings {}
+unsafe impl Sync for Settings {}
+
+impl Default for Settings {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Decoder {
+impl Settings {
     pub fn new() -> Self {
         unsafe {
             let mut settings = mem::MaybeUninit::uninit();
-            let mut dec = mem::MaybeUninit::uninit();
-
             dav1d_default_settings(settings.as_mut_ptr());
+            Settings {
+                dav1d_settings: settings.assume_init(),
+                allocator: None,
+            }
+        }
+    }
+
+    pub fn set_n_threads(&mut self, n_threads: u32) {
+        self.dav1d_settings.n_threads = n_threads as i32;
+    }
 
-            let settings = settings.assume_init();
+    pub fn set_max_frame_delay(&mut self, max_frame_delay: u32) {
+        self.dav1d_settings.max_frame_delay = max_frame_delay as i32;
+    }
 
-            let ret = dav1d_open(dec.as_mut_ptr(), &settings);
+    pub fn set_apply_grain(&mut self, apply_grain: bool) {
+        self.dav1d_settings.apply_grain = apply_grain as i32;
+    }
+
+    pub fn set_operating_point(&mut self, operating_point: u32) {
+        self.dav1d_settings.operating_point = operating_point as i32;
+    }
+
+    pub fn set_all_layers(&mut self, all_layers: bool) {
+        self.dav1d_settings.all_layers = all_layers as i32;
+    }
+
+    pub fn set_frame_size_limit(&mut self, frame_size_limit: u32) {
+        self.dav1d_settings.frame_size_limit = frame_size_limit;
+    }
+
+    pub fn set_output_invisible_frames(&mut self, output_invisible_frames: bool) {
+        self.dav1d_settings.output_invisible_frames = output_invisible_frames as i32;
+    }
+
+    pub fn set_inloop_filters(&mut self, inloop_filters: InloopFilterType) {
+        self.dav1d_settings.inloop_filters = inloop_filters.to_ffi();
+    }
+
+    pub fn set_decode_frame_type(&mut self, decode_frame_type: DecodeFrameType) {
+        self.dav1d_settings.decode_frame_type = decode_frame_type.to_ffi();
+    }
+
+    /// Installs a custom picture allocator so decoded frames land in
+    /// user-owned memory. See [`PicAllocator`].
+    pub fn set_pic_allocator<A: PicAllocator + Send + Sync + 'static>(&mut self, allocator: A) {
+        let boxed: Arc<Box<dyn PicAllocator + Send + Sync>> = Arc::new(Box::new(allocator));
+        let cookie =
+            boxed.as_ref() as *const Box<dyn PicAllocator + Send + Sync> as *mut c_void;
+        self.dav1d_settings.allocator = Dav1dPicAllocator {
+            cookie,
+            alloc_picture_callback: Some(alloc_picture_callback),
+            release_picture_callback: Some(release_picture_callback),
+        };
+        self.allocator = Some(boxed);
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder {
+    /// Creates a decoder with default settings, panicking on failure.
+    pub fn new() -> Self {
+        Self::with_settings(&Settings::default())
+            .expect("Cannot instantiate the default decoder")
+    }
+
+    /// Creates a decoder from the given [`Settings`], returning an error if
+    /// `dav1d_open` fails.
+    pub fn with_settings(settings: &Settings) -> Result<Self, Error> {
+        unsafe {
+            let mut dec = mem::MaybeUninit::uninit();
+
+            let ret = dav1d_open(dec.as_mut_ptr(), &settings.dav1d_settings);
 
             if ret != 0 {
-                panic!("Cannot instantiate the default decoder {}", ret);
+                return Err(Error(ret));
             }
 
-            Decoder {
+            Ok(Decoder {
                 dec: dec.assume_init(),
-            }
+                _allocator: settings.allocator.clone(),
+                pending_data: None,
+            })
         }
     }
 
@@ -73,13 +294,28 @@ impl Decoder {
         }
     }
 
+    /// Feeds one access unit to the decoder.
+    ///
+    /// Returns [`SendState::NeedPicturesDrained`] instead of an error when
+    /// dav1d signals `EAGAIN`: the buffer is retained internally, so drain
+    /// pictures with [`try_get_picture`](Self::try_get_picture) and then call
+    /// [`send_pending`](Self::send_pending) to finish delivering it before
+    /// feeding the next access unit.
+    ///
+    /// Returns [`SendState::NeedPicturesDrained`] immediately (without touching
+    /// `buf`) if an access unit is still pending from an earlier call — resend
+    /// it with [`send_pending`](Self::send_pending) rather than passing a new
+    /// buffer, which would otherwise be lost.
     pub fn send_data<T: AsRef<[u8]>>(
         &mut self,
         buf: T,
         offset: Option<i64>,
         timestamp: Option<i64>,
         duration: Option<i64>,
-    ) -> Result<(), Error> {
+    ) -> Result<SendState, Error> {
+        if self.pending_data.is_some() {
+            return Ok(SendState::NeedPicturesDrained);
+        }
         let buf = buf.as_ref();
         let len = buf.len();
         unsafe {
@@ -95,15 +331,48 @@ impl Decoder {
             if let Some(duration) = duration {
                 data.m.duration = duration;
             }
+            self.pending_data = Some(data);
+        }
+        self.send_pending()
+    }
+
+    /// Resends the access unit previously retained because dav1d returned
+    /// `EAGAIN` or did not consume all of it. A no-op returning [`SendState::Accepted`] when nothing is
+    /// pending.
+    pub fn send_pending(&mut self) -> Result<SendState, Error> {
+        let mut data = match self.pending_data.take() {
+            Some(data) => data,
+            None => return Ok(SendState::Accepted),
+        };
+        unsafe {
             let ret = dav1d_send_data(self.dec, &mut data);
-            if ret < 0 {
-                Err(Error(ret))
+            let err = Error(ret);
+            if ret < 0 && err.is_again() {
+                self.pending_data = Some(data);
+                Ok(SendState::NeedPicturesDrained)
+            } else if ret < 0 {
+                dav1d_data_unref(&mut data);
+                Err(err)
+            } else if data.sz > 0 {
+                // dav1d took only part of the buffer; keep the remainder.
+                self.pending_data = Some(data);
+                Ok(SendState::NeedPicturesDrained)
             } else {
-                Ok(())
+                Ok(SendState::Accepted)
             }
         }
     }
 
+    /// Same as [`get_picture`](Self::get_picture), but returns `Ok(None)`
+    /// rather than an error when dav1d has no picture ready yet (`EAGAIN`).
+    pub fn try_get_picture(&mut self) -> Result<Option<Picture>, Error> {
+        match self.get_picture() {
+            Ok(picture) => Ok(Some(picture)),
+            Err(e) if e.is_again() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn get_picture(&mut self) -> Result<Picture, Error> {
         unsafe {
             let mut pic: Dav1dPicture = mem::zeroed();
@@ -112,7 +381,10 @@ impl Decoder {
             if ret < 0 {
                 Err(Error(ret))
             } else {
-                let inner = InnerPicture { pic };
+                let inner = InnerPicture {
+                    pic,
+                    _allocator: self._allocator.clone(),
+                };
                 Ok(Picture {
                     inner: Arc::new(inner),
                 })
@@ -175,15 +447,29 @@ impl Decoder {
 
 impl Drop for Decoder {
     fn drop(&mut self) {
-        unsafe { dav1d_close(&mut self.dec) };
+        unsafe {
+            if let Some(mut data) = self.pending_data.take() {
+                dav1d_data_unref(&mut data);
+            }
+            dav1d_close(&mut self.dec);
+        }
     }
 }
 
 unsafe impl Send for Decoder {}
 
-#[derive(Debug)]
 struct InnerPicture {
     pub pic: Dav1dPicture,
+    // Keeps the allocator that produced this picture's planes alive until the
+    // picture (and thus `release_picture_callback`) is done, even if the
+    // `Decoder` has already been dropped.
+    _allocator: Option<Arc<Box<dyn PicAllocator + Send + Sync>>>,
+}
+
+impl fmt::Debug for InnerPicture {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("InnerPicture").field("pic", &self.pic).finish()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -200,6 +486,17 @@ pub enum PixelLayout {
     Unknown,
 }
 
+#[allow(non_upper_case_globals)]
+fn pixel_layout_from_ffi(layout: Dav1dPixelLayout) -> PixelLayout {
+    match layout {
+        Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I400 => PixelLayout::I400,
+        Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I420 => PixelLayout::I420,
+        Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I422 => PixelLayout::I422,
+        Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I444 => PixelLayout::I444,
+        _ => PixelLayout::Unknown,
+    }
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 pub enum PlanarImageComponent {
     Y,
@@ -245,6 +542,27 @@ impl AsRef<[u8]> for Plane {
 unsafe impl Send for Plane {}
 unsafe impl Sync for Plane {}
 
+/// A 10/12-bit plane, exposing its rows as `&[u16]` in native endianness.
+#[derive(Clone, Debug)]
+pub struct PlaneU16(Picture, PlanarImageComponent);
+
+impl PlaneU16 {
+    /// Iterates the rows of the plane, yielding exactly the active pixels of
+    /// each row without the stride padding.
+    pub fn rows(&self) -> impl Iterator<Item = &[u16]> + '_ {
+        let (stride, height) = self.0.plane_data_geometry(self.1);
+        let width = self.0.plane_width(self.1) as usize;
+        let ptr = self.0.plane_data_ptr(self.1) as *const u8;
+        (0..height).map(move |y| unsafe {
+            let row = ptr.offset((y * stride) as isize) as *const u16;
+            std::slice::from_raw_parts(row, width)
+        })
+    }
+}
+
+unsafe impl Send for PlaneU16 {}
+unsafe impl Sync for PlaneU16 {}
+
 #[derive(Copy, Clone, Debug)]
 pub struct BitsPerComponent(pub usize);
 
@@ -278,13 +596,80 @@ impl Picture {
         Plane(self.clone(), component)
     }
 
+    /// Width of a plane in pixels, accounting for chroma subsampling.
+    fn plane_width(&self, component: PlanarImageComponent) -> u32 {
+        match component {
+            PlanarImageComponent::Y => self.width(),
+            _ => match self.pixel_layout() {
+                PixelLayout::I420 | PixelLayout::I422 => (self.width() + 1) / 2,
+                PixelLayout::I400 | PixelLayout::I444 => self.width(),
+                PixelLayout::Unknown => unreachable!(),
+            },
+        }
+    }
+
+    /// Number of bytes per sample in the decoded output (1 for 8-bit, 2 for
+    /// 10/12-bit).
+    fn bytes_per_component(&self) -> u32 {
+        if self.bit_depth() > 8 {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Iterates the rows of a plane, yielding exactly the active pixels of each
+    /// row (`width_in_bytes`) without the stride padding.
+    pub fn plane_rows(
+        &self,
+        component: PlanarImageComponent,
+    ) -> impl Iterator<Item = &[u8]> + '_ {
+        let (stride, height) = self.plane_data_geometry(component);
+        let width_bytes = (self.plane_width(component) * self.bytes_per_component()) as usize;
+        let ptr = self.plane_data_ptr(component) as *const u8;
+        (0..height).map(move |y| unsafe {
+            std::slice::from_raw_parts(ptr.offset((y * stride) as isize), width_bytes)
+        })
+    }
+
+    /// Typed accessor for 10/12-bit planes, yielding `&[u16]` rows in dav1d's
+    /// native endianness. Returns `None` for 8-bit pictures.
+    pub fn plane_u16(&self, component: PlanarImageComponent) -> Option<PlaneU16> {
+        match self.bits_per_component() {
+            Some(BitsPerComponent(10)) | Some(BitsPerComponent(12)) => {
+                Some(PlaneU16(self.clone(), component))
+            }
+            _ => None,
+        }
+    }
+
+    /// All planes present in this picture: one for `I400`, three otherwise.
+    ///
+    /// The original request asked for a fixed-size `[Plane; N]`, but `N` is only
+    /// known at run time (it depends on the pixel layout), so this returns a
+    /// `Vec` instead.
+    pub fn planes(&self) -> Vec<Plane> {
+        match self.pixel_layout() {
+            PixelLayout::I400 => vec![self.plane(PlanarImageComponent::Y)],
+            _ => vec![
+                self.plane(PlanarImageComponent::Y),
+                self.plane(PlanarImageComponent::U),
+                self.plane(PlanarImageComponent::V),
+            ],
+        }
+    }
+
     pub fn bit_depth(&self) -> usize {
         (*self.inner).pic.p.bpc as usize
     }
 
     pub fn bits_per_component(&self) -> Option<BitsPerComponent> {
         unsafe {
-            match (*(*self.inner).pic.seq_hdr).hbd {
+            let seq = (*self.inner).pic.seq_hdr;
+            if seq.is_null() {
+                return None;
+            }
+            match (*seq).hbd {
                 0 => Some(BitsPerComponent(8)),
                 1 => Some(BitsPerComponent(10)),
                 2 => Some(BitsPerComponent(12)),
@@ -302,14 +687,7 @@ impl Picture {
     }
 
     pub fn pixel_layout(&self) -> PixelLayout {
-        #[allow(non_upper_case_globals)]
-        match (*self.inner).pic.p.layout {
-            Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I400 => PixelLayout::I400,
-            Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I420 => PixelLayout::I420,
-            Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I422 => PixelLayout::I422,
-            Dav1dPixelLayout_DAV1D_PIXEL_LAYOUT_I444 => PixelLayout::I444,
-            _ => PixelLayout::Unknown,
-        }
+        pixel_layout_from_ffi((*self.inner).pic.p.layout)
     }
 
     pub fn timestamp(&self) -> Option<i64> {
@@ -328,6 +706,114 @@ impl Picture {
     pub fn offset(&self) -> i64 {
         (*self.inner).pic.m.offset
     }
+
+    /// Colour description carried by this picture's sequence header, if any.
+    pub fn color_description(&self) -> Option<ColorDescription> {
+        unsafe {
+            let seq = (*self.inner).pic.seq_hdr;
+            if seq.is_null() {
+                None
+            } else {
+                Some(color_description_from_seq(&*seq))
+            }
+        }
+    }
+
+    /// Content light level side data (CLL), if present.
+    pub fn content_light_level(&self) -> Option<ContentLightLevel> {
+        unsafe {
+            let ptr = (*self.inner).pic.content_light;
+            if ptr.is_null() {
+                None
+            } else {
+                Some(ContentLightLevel {
+                    max_content_light_level: (*ptr).max_content_light_level,
+                    max_frame_average_light_level: (*ptr).max_frame_average_light_level,
+                })
+            }
+        }
+    }
+
+    /// Mastering display side data (MDCV), if present.
+    pub fn mastering_display(&self) -> Option<MasteringDisplay> {
+        unsafe {
+            let ptr = (*self.inner).pic.mastering_display;
+            if ptr.is_null() {
+                None
+            } else {
+                Some(MasteringDisplay {
+                    primaries: (*ptr).primaries,
+                    white_point: (*ptr).white_point,
+                    max_luminance: (*ptr).max_luminance,
+                    min_luminance: (*ptr).min_luminance,
+                })
+            }
+        }
+    }
+
+    /// ITU-T T.35 side data messages, if present. The returned slice borrows
+    /// from this `Picture` and stays valid for as long as it is held.
+    pub fn itut_t35(&self) -> Option<&[ItutT35]> {
+        unsafe {
+            let ptr = (*self.inner).pic.itut_t35;
+            if ptr.is_null() {
+                None
+            } else {
+                let len = (*self.inner).pic.n_itut_t35 as usize;
+                Some(std::slice::from_raw_parts(ptr as *const ItutT35, len))
+            }
+        }
+    }
+}
+
+/// Content light level (CLL) side data.
+#[derive(Debug, Clone, Copy)]
+pub struct ContentLightLevel {
+    pub max_content_light_level: u16,
+    pub max_frame_average_light_level: u16,
+}
+
+/// Mastering display colour volume (MDCV) side data.
+#[derive(Debug, Clone, Copy)]
+pub struct MasteringDisplay {
+    pub primaries: [[u16; 2]; 3],
+    pub white_point: [u16; 2],
+    pub max_luminance: u32,
+    pub min_luminance: u32,
+}
+
+/// A single ITU-T T.35 metadata message, borrowed from a [`Picture`].
+#[repr(transparent)]
+pub struct ItutT35(Dav1dITUTT35);
+
+impl ItutT35 {
+    pub fn country_code(&self) -> u8 {
+        self.0.country_code
+    }
+
+    pub fn country_code_extension_byte(&self) -> u8 {
+        self.0.country_code_extension_byte
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.0.payload, self.0.payload_size) }
+    }
+}
+
+unsafe impl Send for ItutT35 {}
+unsafe impl Sync for ItutT35 {}
+
+impl fmt::Debug for ItutT35 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ItutT35")
+            .field("country_code", &self.country_code())
+            .field(
+                "country_code_extension_byte",
+                &self.country_code_extension_byte(),
+            )
+            .field("payload_size", &self.payload().len())
+            .finish()
+    }
 }
 
 unsafe impl Send for Picture {}
@@ -355,12 +841,232 @@ pub fn parse_sequence_header<T: AsRef<[u8]>>(buf: T) -> Result<SequenceHeader, E
     }
 }
 
+/// AV1 colour primaries (CICP `colour_primaries`).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ColorPrimaries {
+    Bt709,
+    Unspecified,
+    Bt470M,
+    Bt470Bg,
+    Bt601,
+    Smpte240,
+    GenericFilm,
+    Bt2020,
+    Xyz,
+    Smpte431,
+    Smpte432,
+    Ebu3213,
+    Reserved(u32),
+}
+
+impl ColorPrimaries {
+    fn from_cicp(value: u32) -> Self {
+        match value {
+            1 => ColorPrimaries::Bt709,
+            2 => ColorPrimaries::Unspecified,
+            4 => ColorPrimaries::Bt470M,
+            5 => ColorPrimaries::Bt470Bg,
+            6 => ColorPrimaries::Bt601,
+            7 => ColorPrimaries::Smpte240,
+            8 => ColorPrimaries::GenericFilm,
+            9 => ColorPrimaries::Bt2020,
+            10 => ColorPrimaries::Xyz,
+            11 => ColorPrimaries::Smpte431,
+            12 => ColorPrimaries::Smpte432,
+            22 => ColorPrimaries::Ebu3213,
+            other => ColorPrimaries::Reserved(other),
+        }
+    }
+}
+
+/// AV1 transfer characteristics (CICP `transfer_characteristics`).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TransferCharacteristics {
+    Bt709,
+    Unspecified,
+    Bt470M,
+    Bt470Bg,
+    Bt601,
+    Smpte240,
+    Linear,
+    Log100,
+    Log100Sqrt10,
+    Iec61966,
+    Bt1361,
+    Srgb,
+    Bt2020_10Bit,
+    Bt2020_12Bit,
+    Smpte2084,
+    Smpte428,
+    Hlg,
+    Reserved(u32),
+}
+
+impl TransferCharacteristics {
+    fn from_cicp(value: u32) -> Self {
+        match value {
+            1 => TransferCharacteristics::Bt709,
+            2 => TransferCharacteristics::Unspecified,
+            4 => TransferCharacteristics::Bt470M,
+            5 => TransferCharacteristics::Bt470Bg,
+            6 => TransferCharacteristics::Bt601,
+            7 => TransferCharacteristics::Smpte240,
+            8 => TransferCharacteristics::Linear,
+            9 => TransferCharacteristics::Log100,
+            10 => TransferCharacteristics::Log100Sqrt10,
+            11 => TransferCharacteristics::Iec61966,
+            12 => TransferCharacteristics::Bt1361,
+            13 => TransferCharacteristics::Srgb,
+            14 => TransferCharacteristics::Bt2020_10Bit,
+            15 => TransferCharacteristics::Bt2020_12Bit,
+            16 => TransferCharacteristics::Smpte2084,
+            17 => TransferCharacteristics::Smpte428,
+            18 => TransferCharacteristics::Hlg,
+            other => TransferCharacteristics::Reserved(other),
+        }
+    }
+}
+
+/// AV1 matrix coefficients (CICP `matrix_coefficients`).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum MatrixCoefficients {
+    Identity,
+    Bt709,
+    Unspecified,
+    Fcc,
+    Bt470Bg,
+    Bt601,
+    Smpte240,
+    YCgCo,
+    Bt2020Ncl,
+    Bt2020Cl,
+    Smpte2085,
+    ChromatNcl,
+    ChromatCl,
+    ICtCp,
+    Reserved(u32),
+}
+
+impl MatrixCoefficients {
+    fn from_cicp(value: u32) -> Self {
+        match value {
+            0 => MatrixCoefficients::Identity,
+            1 => MatrixCoefficients::Bt709,
+            2 => MatrixCoefficients::Unspecified,
+            4 => MatrixCoefficients::Fcc,
+            5 => MatrixCoefficients::Bt470Bg,
+            6 => MatrixCoefficients::Bt601,
+            7 => MatrixCoefficients::Smpte240,
+            8 => MatrixCoefficients::YCgCo,
+            9 => MatrixCoefficients::Bt2020Ncl,
+            10 => MatrixCoefficients::Bt2020Cl,
+            11 => MatrixCoefficients::Smpte2085,
+            12 => MatrixCoefficients::ChromatNcl,
+            13 => MatrixCoefficients::ChromatCl,
+            14 => MatrixCoefficients::ICtCp,
+            other => MatrixCoefficients::Reserved(other),
+        }
+    }
+}
+
+/// Sample range of the luma/chroma values.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ColorRange {
+    Limited,
+    Full,
+}
+
+impl ColorRange {
+    fn from_ffi(value: i32) -> Self {
+        if value != 0 {
+            ColorRange::Full
+        } else {
+            ColorRange::Limited
+        }
+    }
+}
+
+/// Position of the chroma samples relative to luma.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ChromaSamplePosition {
+    Unknown,
+    Vertical,
+    Colocated,
+    Reserved(u32),
+}
+
+impl ChromaSamplePosition {
+    fn from_ffi(value: u32) -> Self {
+        match value {
+            0 => ChromaSamplePosition::Unknown,
+            1 => ChromaSamplePosition::Vertical,
+            2 => ChromaSamplePosition::Colocated,
+            other => ChromaSamplePosition::Reserved(other),
+        }
+    }
+}
+
+/// Full colour description shared by [`SequenceHeader`] and [`Picture`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct ColorDescription {
+    pub color_primaries: ColorPrimaries,
+    pub transfer_characteristics: TransferCharacteristics,
+    pub matrix_coefficients: MatrixCoefficients,
+    pub color_range: ColorRange,
+}
+
+fn color_description_from_seq(seq: &Dav1dSequenceHeader) -> ColorDescription {
+    ColorDescription {
+        color_primaries: ColorPrimaries::from_cicp(seq.pri as u32),
+        transfer_characteristics: TransferCharacteristics::from_cicp(seq.trc as u32),
+        matrix_coefficients: MatrixCoefficients::from_cicp(seq.mtrx as u32),
+        color_range: ColorRange::from_ffi(seq.color_range as i32),
+    }
+}
+
+fn bit_depth_from_hbd(hbd: i32) -> usize {
+    match hbd {
+        0 => 8,
+        1 => 10,
+        2 => 12,
+        _ => 8,
+    }
+}
+
 #[derive(Debug)]
 pub struct SequenceHeader {
     seq: Arc<Dav1dSequenceHeader>,
 }
 
-impl SequenceHeader {}
+impl SequenceHeader {
+    pub fn profile(&self) -> u8 {
+        self.seq.profile as u8
+    }
+
+    pub fn max_width(&self) -> u32 {
+        self.seq.max_width as u32
+    }
+
+    pub fn max_height(&self) -> u32 {
+        self.seq.max_height as u32
+    }
+
+    pub fn bit_depth(&self) -> usize {
+        bit_depth_from_hbd(self.seq.hbd as i32)
+    }
+
+    pub fn pixel_layout(&self) -> PixelLayout {
+        pixel_layout_from_ffi(self.seq.layout)
+    }
+
+    pub fn chroma_sample_position(&self) -> ChromaSamplePosition {
+        ChromaSamplePosition::from_ffi(self.seq.chr as u32)
+    }
+
+    pub fn color_description(&self) -> ColorDescription {
+        color_description_from_seq(&self.seq)
+    }
+}
 
 impl Drop for SequenceHeader {
     fn drop(&mut self) {